@@ -1,6 +1,7 @@
 // 允许使用 cocoa crate 的 deprecated API（迁移到 objc2 需要较大改动）
 #![allow(deprecated)]
 
+use std::collections::HashMap;
 use std::sync::Mutex;
 use tauri::{
     menu::{Menu, MenuItem},
@@ -22,9 +23,16 @@ use objc::sel_impl;
 const STORE_PATH: &str = "settings.json";
 const WINDOW_POSITION_KEY: &str = "window_position";
 const SHORTCUTS_KEY: &str = "shortcuts";
+const POST_PROCESSING_KEY: &str = "post_processing";
 const DEFAULT_X: f64 = 100.0;
 const DEFAULT_Y: f64 = 100.0;
 const SHORTCUT_TOGGLE_RECORDING: &str = "toggle_recording";
+const ACTION_TOGGLE_RECORDING: &str = "toggle_recording";
+const ACTION_CANCEL_RECORDING: &str = "cancel_recording";
+const EVENT_RECORDING_START: &str = "recording-start";
+const EVENT_RECORDING_STOP: &str = "recording-stop";
+const EVENT_CANCEL_RECORDING: &str = "cancel-recording";
+const EVENT_MENU_ACTION: &str = "menu-action";
 
 /// Global state for managing the backend sidecar process
 struct SidecarState {
@@ -36,6 +44,11 @@ struct PortState {
     port: Mutex<u16>,
 }
 
+/// 跟踪每个快捷键 action 当前是否处于按下状态，用于过滤系统在长按时重复发出的 Pressed 事件
+struct ShortcutKeyState {
+    is_down: Mutex<HashMap<String, bool>>,
+}
+
 fn find_available_port() -> Result<u16, std::io::Error> {
     let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
     let port = listener.local_addr()?.port();
@@ -49,17 +62,30 @@ struct WindowPosition {
     y: f64,
 }
 
+fn default_shortcut_mode() -> String {
+    "toggle".to_string()
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct ShortcutConfig {
     modifiers: Vec<String>,  // ["Alt"], ["Ctrl", "Shift"]
     key: String,             // "F5", "A"
+    #[serde(default = "default_shortcut_mode")]
+    mode: String,            // "toggle" | "hold"
 }
 
+/// action（"toggle_recording" / "push_to_talk" / "cancel_recording" / 自定义）到快捷键配置的映射
+type ShortcutsSettings = HashMap<String, ShortcutConfig>;
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-struct ShortcutsSettings {
-    toggle_recording: Option<ShortcutConfig>,
+struct PostProcessingConfig {
+    command: String,
+    enabled: bool,
 }
 
+/// action（如 "default" / "translate"）到后处理命令配置的映射
+type PostProcessingSettings = HashMap<String, PostProcessingConfig>;
+
 #[tauri::command]
 fn greet(name: &str) -> String {
     format!("Hello, {}! Welcome to Yap.", name)
@@ -122,7 +148,44 @@ fn set_window_bounds(window: tauri::Window, x: f64, y: f64, width: f64, height:
         }
         Ok(())
     }
-    #[cfg(not(target_os = "macos"))]
+    #[cfg(target_os = "windows")]
+    {
+        use tauri::{PhysicalPosition, PhysicalSize};
+        use windows::Win32::Foundation::POINT;
+        use windows::Win32::Graphics::Gdi::{MonitorFromPoint, MONITOR_DEFAULTTONEAREST, MONITOR_DEFAULTTOPRIMARY};
+        use windows::Win32::UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI};
+
+        // 前端是按主屏幕的缩放比例算出的逻辑坐标，而 MonitorFromPoint 要的是物理像素，
+        // 所以先用主屏幕的 DPI 把传入的 x/y 换算成物理坐标，再去定位目标显示器
+        let primary_monitor = unsafe { MonitorFromPoint(POINT { x: 0, y: 0 }, MONITOR_DEFAULTTOPRIMARY) };
+        let mut primary_dpi_x: u32 = 96;
+        let mut primary_dpi_y: u32 = 96;
+        unsafe { GetDpiForMonitor(primary_monitor, MDT_EFFECTIVE_DPI, &mut primary_dpi_x, &mut primary_dpi_y) }
+            .map_err(|e| e.to_string())?;
+        let primary_scale_factor = primary_dpi_x as f64 / 96.0;
+
+        let physical_point = POINT {
+            x: (x * primary_scale_factor) as i32,
+            y: (y * primary_scale_factor) as i32,
+        };
+        let monitor = unsafe { MonitorFromPoint(physical_point, MONITOR_DEFAULTTONEAREST) };
+
+        let mut dpi_x: u32 = 96;
+        let mut dpi_y: u32 = 96;
+        unsafe { GetDpiForMonitor(monitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y) }
+            .map_err(|e| e.to_string())?;
+        let target_scale_factor = dpi_x as f64 / 96.0;
+
+        // 位置已经是物理坐标（Windows 虚拟桌面坐标系本身与各显示器 DPI 无关），
+        // 尺寸则要按目标显示器的缩放比例换算，这样窗口在目标显示器上才是期望的逻辑大小
+        let physical_size = PhysicalSize::new(width * target_scale_factor, height * target_scale_factor);
+        let physical_position = PhysicalPosition::new(physical_point.x as f64, physical_point.y as f64);
+
+        window.set_size(physical_size).map_err(|e| e.to_string())?;
+        window.set_position(physical_position).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
     {
         use tauri::{LogicalPosition, LogicalSize};
         window.set_size(LogicalSize::new(width, height)).map_err(|e| e.to_string())?;
@@ -137,7 +200,10 @@ fn set_ignore_cursor_events(window: tauri::Window, ignore: bool) -> Result<(), S
     window.set_ignore_cursor_events(ignore).map_err(|e| e.to_string())
 }
 
-/// 获取全局鼠标位置
+/// 获取全局鼠标位置。注意坐标单位因平台而异：macOS 的 CGEvent 返回的是 points（逻辑坐标），
+/// Windows/Linux 返回的是物理像素。`set_window_bounds` 在 Windows 上把传入的 x/y 当作按主屏幕
+/// 缩放比例计算的逻辑坐标，在 Linux 上当作逻辑坐标，所以在 Windows/Linux 上这个值与
+/// `set_window_bounds` 期望的单位不同，直接传入前需要按缩放比例换算
 #[tauri::command]
 fn get_cursor_position() -> Result<WindowPosition, String> {
     #[cfg(target_os = "macos")]
@@ -152,7 +218,25 @@ fn get_cursor_position() -> Result<WindowPosition, String> {
         let point = event.location();
         Ok(WindowPosition { x: point.x, y: point.y })
     }
-    #[cfg(not(target_os = "macos"))]
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Foundation::POINT;
+        use windows::Win32::UI::WindowsAndMessaging::GetCursorPos;
+
+        let mut point = POINT::default();
+        unsafe { GetCursorPos(&mut point).map_err(|e| e.to_string())? };
+        Ok(WindowPosition { x: point.x as f64, y: point.y as f64 })
+    }
+    #[cfg(target_os = "linux")]
+    {
+        use mouse_position::mouse_position::Mouse;
+
+        match Mouse::get_mouse_position() {
+            Mouse::Position { x, y } => Ok(WindowPosition { x: x as f64, y: y as f64 }),
+            Mouse::Error => Err("Failed to read cursor position".to_string()),
+        }
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
     {
         Err("Not supported on this platform".to_string())
     }
@@ -228,7 +312,8 @@ fn copy_to_clipboard(app: tauri::AppHandle, text: String) -> Result<(), String>
         .map_err(|e| format!("Failed to copy to clipboard: {}", e))
 }
 
-/// 模拟键盘输入文本（macOS）
+/// 模拟键盘输入文本：macOS 上走 CGEvent（能处理 enigo 按键事件覆盖不到的任意 Unicode 字符），
+/// Windows/Linux 上通过 enigo 的 Unicode 文本输入实现
 #[tauri::command]
 fn input_text(text: String, typewriter: Option<bool>) -> Result<(), String> {
     #[cfg(target_os = "macos")]
@@ -263,9 +348,165 @@ fn input_text(text: String, typewriter: Option<bool>) -> Result<(), String> {
     }
     #[cfg(not(target_os = "macos"))]
     {
-        let _ = text;
-        let _ = typewriter;
-        Err("Text input not supported on this platform".to_string())
+        use enigo::{Enigo, Keyboard, Settings};
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let mut enigo = Enigo::new(&Settings::default()).map_err(|e| e.to_string())?;
+        let use_typewriter = typewriter.unwrap_or(true);
+
+        if use_typewriter {
+            // enigo 没有单独的按键抬起事件可逐字符等待，按 grapheme 切块以保持逐字效果
+            for grapheme in text.graphemes(true) {
+                enigo.text(grapheme).map_err(|e| e.to_string())?;
+                std::thread::sleep(std::time::Duration::from_millis(16));
+            }
+        } else {
+            enigo.text(&text).map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// 检查本进程是否已被系统信任（Accessibility 权限；Input Monitoring 权限不在检查范围内），
+/// 在 `input_text` / `get_cursor_position` 依赖的 CGEvent 静默失效前提醒用户授权。
+/// `prompt` 为 true 时会触发系统的授权弹窗。
+#[tauri::command]
+fn check_accessibility_permissions(prompt: bool) -> bool {
+    #[cfg(target_os = "macos")]
+    {
+        macos_accessibility_client::accessibility::application_is_trusted_with_prompt(prompt)
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = prompt;
+        true
+    }
+}
+
+/// 获取前台应用当前选中的文本，用于"对选中内容口述替换"的工作流
+#[tauri::command]
+fn get_selection_text(app: tauri::AppHandle) -> Result<String, String> {
+    #[cfg(target_os = "macos")]
+    {
+        use core_graphics::event::{CGEvent, CGEventFlags, CGEventTapLocation};
+        use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+        use tauri_plugin_clipboard_manager::ClipboardExt;
+
+        const NO_SELECTION_SENTINEL: &str = "__yap_get_selection_text_sentinel__";
+
+        let clipboard = app.clipboard();
+        // 先保存当前剪贴板的文本用于结束后还原（只保存和还原纯文本，剪贴板原本不是文本时不还原）；
+        // 再写入一个哨兵值，如果前台应用没有选区，
+        // 合成的 Cmd+C 会是空操作，剪贴板会原样保留哨兵值而不是用户之前的内容，
+        // 这样才能和"有选区但复制出了原内容"区分开
+        let original = clipboard.read_text().ok();
+        clipboard
+            .write_text(NO_SELECTION_SENTINEL.to_string())
+            .map_err(|e| e.to_string())?;
+
+        let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+            .map_err(|_| "Failed to create event source")?;
+
+        // virtual keycode 8 = 'C'
+        let key_down = CGEvent::new_keyboard_event(source.clone(), 8, true)
+            .map_err(|_| "Failed to create key down event")?;
+        key_down.set_flags(CGEventFlags::CGEventFlagCommand);
+        key_down.post(CGEventTapLocation::HID);
+
+        let key_up = CGEvent::new_keyboard_event(source, 8, false)
+            .map_err(|_| "Failed to create key up event")?;
+        key_up.set_flags(CGEventFlags::CGEventFlagCommand);
+        key_up.post(CGEventTapLocation::HID);
+
+        // 给目标应用留出时间把选中内容写入剪贴板
+        std::thread::sleep(std::time::Duration::from_millis(100));
+
+        let selection = clipboard.read_text().map_err(|e| e.to_string())?;
+
+        if let Some(original_text) = original {
+            let _ = clipboard.write_text(original_text);
+        }
+
+        if selection == NO_SELECTION_SENTINEL {
+            Ok(String::new())
+        } else {
+            Ok(selection)
+        }
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = app;
+        Err("Get selection text not supported on this platform".to_string())
+    }
+}
+
+/// 把文本写入已配置的外部命令的 stdin，收集其 stdout 作为处理结果
+fn run_post_processing_command(app: &tauri::AppHandle, command: &str, input: &str) -> Result<String, String> {
+    use std::time::Duration;
+    use tauri_plugin_shell::process::CommandEvent;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    // command 是用户配置的完整命令行（可能带参数/管道），而 `.command()` 只接受可执行文件名，
+    // 所以通过系统 shell 来解释整条命令行，而不是把它整体当成程序名传给 `.command()`
+    #[cfg(target_os = "windows")]
+    let shell_command = app.shell().command("cmd").args(["/C", command]);
+    #[cfg(not(target_os = "windows"))]
+    let shell_command = app.shell().command("sh").args(["-c", command]);
+
+    let (mut rx, mut child) = shell_command.spawn().map_err(|e| e.to_string())?;
+    child.write(input.as_bytes()).map_err(|e| e.to_string())?;
+    // 写完立即丢弃 stdin 句柄让子进程收到 EOF；否则读完整个 stdin 才输出的命令
+    // （格式化器、翻译器、LLM CLI，正是这个功能要支持的场景）会永远阻塞在读取上
+    drop(child);
+
+    let collect = async {
+        let mut output = String::new();
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Stdout(line) => {
+                    output.push_str(&String::from_utf8_lossy(&line));
+                    output.push('\n');
+                }
+                CommandEvent::Terminated(payload) => {
+                    if payload.code != Some(0) {
+                        return Err(format!("post-processing command exited with {:?}", payload.code));
+                    }
+                    break;
+                }
+                _ => {}
+            }
+        }
+        Ok(output.trim_end_matches('\n').to_string())
+    };
+
+    tauri::async_runtime::block_on(async move {
+        match tokio::time::timeout(TIMEOUT, collect).await {
+            Ok(result) => result,
+            Err(_) => Err("post-processing command timed out".to_string()),
+        }
+    })
+}
+
+/// 将转写文本通过用户配置的外部命令进行后处理（如格式化、翻译、接入 LLM CLI），
+/// 再进入剪贴板或 `input_text`。命令执行失败或未启用时回退到原始文本，避免错误配置吞掉一次听写。
+#[tauri::command]
+fn pipe_text(app: tauri::AppHandle, text: String, action: String) -> Result<String, String> {
+    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
+    let settings: PostProcessingSettings = store
+        .get(POST_PROCESSING_KEY)
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default();
+
+    let config = match settings.get(&action) {
+        Some(config) if config.enabled && !config.command.trim().is_empty() => config,
+        _ => return Ok(text),
+    };
+
+    match run_post_processing_command(&app, &config.command, &text) {
+        Ok(output) => Ok(output),
+        Err(_) => Ok(text),
     }
 }
 
@@ -343,31 +584,98 @@ fn get_shortcut_settings(app: tauri::AppHandle) -> ShortcutsSettings {
     }
 }
 
+/// 根据 action 当前绑定的 mode 处理一次快捷键事件，并发出对应事件
+///
+/// 操作系统在物理按键持续按下期间可能重复发出 `Pressed` 事件（按键重复），
+/// 这里用 `is_down` 过滤掉重复的 `Pressed`，只在真正的按下/释放边沿发出事件，
+/// 否则 hold 模式下录音会被反复打开又关闭。
+fn handle_shortcut_event(
+    app_handle: &tauri::AppHandle,
+    key_state: &ShortcutKeyState,
+    action: &str,
+    mode: &str,
+    state: ShortcutState,
+) {
+    let mut is_down = key_state.is_down.lock().unwrap();
+    match state {
+        ShortcutState::Pressed => {
+            if *is_down.get(action).unwrap_or(&false) {
+                return;
+            }
+            is_down.insert(action.to_string(), true);
+            drop(is_down);
+
+            if mode == "hold" {
+                let _ = app_handle.emit(EVENT_RECORDING_START, ());
+            } else if action == ACTION_CANCEL_RECORDING {
+                let _ = app_handle.emit(EVENT_CANCEL_RECORDING, ());
+            } else {
+                let _ = app_handle.emit(SHORTCUT_TOGGLE_RECORDING, ());
+            }
+        }
+        ShortcutState::Released => {
+            is_down.insert(action.to_string(), false);
+            drop(is_down);
+
+            if mode == "hold" {
+                let _ = app_handle.emit(EVENT_RECORDING_STOP, ());
+            }
+        }
+    }
+}
+
+/// 为单个 action 注册全局快捷键，在 Pressed/Released 上分发到 `handle_shortcut_event`
+fn register_action_shortcut(
+    app: &tauri::AppHandle,
+    action: String,
+    mode: String,
+    shortcut: Shortcut,
+) -> tauri::Result<()> {
+    let app_handle = app.clone();
+    app.global_shortcut().on_shortcut(shortcut, move |app, _shortcut, event| {
+        let key_state = app.state::<ShortcutKeyState>();
+        handle_shortcut_event(&app_handle, &key_state, &action, &mode, event.state);
+    })
+}
+
 /// 更新快捷键
 #[tauri::command]
-fn update_shortcut(app: tauri::AppHandle, modifiers: Vec<String>, key: String) -> Result<(), String> {
+fn update_shortcut(
+    app: tauri::AppHandle,
+    action: String,
+    modifiers: Vec<String>,
+    key: String,
+    mode: Option<String>,
+) -> Result<(), String> {
     // 解析新快捷键
     let mods = parse_modifiers(&modifiers);
     let code = parse_key(&key).ok_or("Invalid key")?;
     let new_shortcut = Shortcut::new(Some(mods), code);
+    let mode = mode.unwrap_or_else(default_shortcut_mode);
 
-    // 注销所有现有快捷键
-    let global_shortcut = app.global_shortcut();
-    global_shortcut.unregister_all().map_err(|e| e.to_string())?;
+    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
+    let mut settings: ShortcutsSettings = store
+        .get(SHORTCUTS_KEY)
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default();
 
-    // 注册新快捷键
-    let app_handle = app.clone();
-    global_shortcut.on_shortcut(new_shortcut, move |_app, _shortcut, event| {
-        if event.state == ShortcutState::Pressed {
-            let _ = app_handle.emit(SHORTCUT_TOGGLE_RECORDING, ());
+    // 注销该 action 之前绑定的快捷键，避免旧快捷键残留并继续触发
+    let global_shortcut = app.global_shortcut();
+    if let Some(old_config) = settings.get(&action) {
+        if let Some(old_code) = parse_key(&old_config.key) {
+            let old_shortcut = Shortcut::new(Some(parse_modifiers(&old_config.modifiers)), old_code);
+            let _ = global_shortcut.unregister(old_shortcut);
         }
-    }).map_err(|e| e.to_string())?;
+    } else if action == ACTION_TOGGLE_RECORDING {
+        // 启动时注册的默认 Alt+F5 没有写入 store，这里同样需要注销
+        let _ = global_shortcut.unregister(Shortcut::new(Some(Modifiers::ALT), Code::F5));
+    }
+
+    register_action_shortcut(&app, action.clone(), mode.clone(), new_shortcut).map_err(|e| e.to_string())?;
 
     // 保存到 store
-    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
-    let config = ShortcutConfig { modifiers, key };
-    let settings = ShortcutsSettings { toggle_recording: Some(config) };
-    store.set(SHORTCUTS_KEY, serde_json::to_value(settings).unwrap());
+    settings.insert(action, ShortcutConfig { modifiers, key, mode });
+    store.set(SHORTCUTS_KEY, serde_json::to_value(&settings).unwrap());
     store.save().map_err(|e| e.to_string())?;
 
     Ok(())
@@ -386,6 +694,9 @@ pub fn run() {
         .manage(PortState {
             port: Mutex::new(8765),
         })
+        .manage(ShortcutKeyState {
+            is_down: Mutex::new(HashMap::new()),
+        })
         .setup(|app| {
             let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
             let show = MenuItem::with_id(app, "show", "Show Window", true, None::<&str>)?;
@@ -428,6 +739,37 @@ pub fn run() {
                 if let Some(window) = app.get_webview_window("main") {
                     let _ = window.set_shadow(false);
                 }
+
+                // 原生 macOS 菜单栏：Yap 菜单提供 Cmd+, 打开设置、Cmd+Q 退出，
+                // Edit 菜单提供标准的 Copy/Paste（系统预定义项，作用于当前焦点输入框）
+                use tauri::menu::{PredefinedMenuItem, Submenu};
+
+                let settings_item = MenuItem::with_id(app, "menu_settings", "Settings...", true, Some("Cmd+,"))?;
+                let quit_item = MenuItem::with_id(app, "menu_quit", "Quit", true, Some("Cmd+Q"))?;
+                let app_menu = Submenu::with_items(app, "Yap", true, &[&settings_item, &quit_item])?;
+
+                let copy_item = PredefinedMenuItem::copy(app, None)?;
+                let paste_item = PredefinedMenuItem::paste(app, None)?;
+                let edit_menu = Submenu::with_items(app, "Edit", true, &[&copy_item, &paste_item])?;
+
+                let app_menu_bar = Menu::with_items(app, &[&app_menu, &edit_menu])?;
+                app.set_menu(app_menu_bar)?;
+
+                app.on_menu_event(|app, event| {
+                    // app.on_menu_event 是全局处理器，托盘菜单（"quit"/"show"）的事件也会经过这里，
+                    // 这些已经由托盘自己的 on_menu_event 处理过了，这里只处理/广播 App 菜单自己的 id
+                    match event.id.as_ref() {
+                        "menu_settings" => {
+                            let _ = app.emit(EVENT_MENU_ACTION, event.id.as_ref());
+                            let _ = open_settings_window(app.clone());
+                        }
+                        "menu_quit" => {
+                            let _ = app.emit(EVENT_MENU_ACTION, event.id.as_ref());
+                            app.exit(0);
+                        }
+                        _ => {}
+                    }
+                });
             }
 
             // Determine backend port: use 8765 in dev, find available port in production
@@ -479,30 +821,30 @@ pub fn run() {
                 }
             });
 
-            // 从 store 读取快捷键配置，如果没有则使用默认值 Alt+F5
+            // 从 store 读取快捷键配置，如果没有 toggle_recording 绑定则使用默认值 Alt+F5
             let store = app.store(STORE_PATH)?;
-            let shortcuts_settings: ShortcutsSettings = store
+            let mut shortcuts_settings: ShortcutsSettings = store
                 .get(SHORTCUTS_KEY)
                 .and_then(|v| serde_json::from_value(v.clone()).ok())
                 .unwrap_or_default();
 
-            let (mods, code) = if let Some(config) = shortcuts_settings.toggle_recording {
-                (parse_modifiers(&config.modifiers), parse_key(&config.key).unwrap_or(Code::F5))
-            } else {
-                (Modifiers::ALT, Code::F5)
-            };
+            shortcuts_settings.entry(ACTION_TOGGLE_RECORDING.to_string()).or_insert_with(|| ShortcutConfig {
+                modifiers: vec!["Alt".to_string()],
+                key: "F5".to_string(),
+                mode: default_shortcut_mode(),
+            });
 
-            let shortcut = Shortcut::new(Some(mods), code);
             let app_handle = app.handle().clone();
-            app.global_shortcut().on_shortcut(shortcut, move |_app, _shortcut, event| {
-                if event.state == ShortcutState::Pressed {
-                    let _ = app_handle.emit(SHORTCUT_TOGGLE_RECORDING, ());
-                }
-            })?;
+            for (action, config) in shortcuts_settings {
+                let mods = parse_modifiers(&config.modifiers);
+                let code = parse_key(&config.key).unwrap_or(Code::F5);
+                let shortcut = Shortcut::new(Some(mods), code);
+                register_action_shortcut(&app_handle, action, config.mode, shortcut)?;
+            }
 
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![greet, get_backend_port, save_window_position, load_window_position, set_window_bounds, set_ignore_cursor_events, get_cursor_position, open_settings_window, close_settings_window, broadcast_settings_changed, get_shortcut_settings, update_shortcut, open_devtools, input_text, copy_to_clipboard])
+        .invoke_handler(tauri::generate_handler![greet, get_backend_port, save_window_position, load_window_position, set_window_bounds, set_ignore_cursor_events, get_cursor_position, open_settings_window, close_settings_window, broadcast_settings_changed, get_shortcut_settings, update_shortcut, open_devtools, input_text, copy_to_clipboard, check_accessibility_permissions, get_selection_text, pipe_text])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
         .run(|app, event| {